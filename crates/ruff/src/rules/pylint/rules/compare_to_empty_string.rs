@@ -1,10 +1,10 @@
 use anyhow::bail;
 use itertools::Itertools;
-use rustpython_parser::ast::{Cmpop, Constant, Expr, ExprKind};
+use rustpython_parser::ast::{Cmpop, Expr};
 
-use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_diagnostics::{AlwaysAutofixableViolation, Diagnostic, Fix};
 use ruff_macros::{derive_message_formats, violation};
-use ruff_python_ast::helpers::{unparse_constant, unparse_expr};
+use ruff_python_ast::helpers::{static_truthiness, unparse_expr};
 use ruff_python_ast::types::Range;
 
 use crate::checkers::ast::Checker;
@@ -34,8 +34,10 @@ impl TryFrom<&Cmpop> for EmptyStringCmpop {
 impl EmptyStringCmpop {
     pub fn into_unary(self) -> &'static str {
         match self {
-            Self::Is | Self::Eq => "",
-            Self::IsNot | Self::NotEq => "not ",
+            // `x == ""` is true exactly when `x` is falsey.
+            Self::Is | Self::Eq => "not ",
+            // `x != ""` is true exactly when `x` is truthy.
+            Self::IsNot | Self::NotEq => "",
         }
     }
 }
@@ -58,78 +60,128 @@ pub struct CompareToEmptyString {
     pub replacement: String,
 }
 
-impl Violation for CompareToEmptyString {
+impl AlwaysAutofixableViolation for CompareToEmptyString {
     #[derive_message_formats]
     fn message(&self) -> String {
         format!(
-            "`{}` can be simplified to `{}` as an empty string is falsey",
+            "`{}` can be simplified to `{}` as the literal is always falsey",
             self.existing, self.replacement,
         )
     }
+
+    fn autofix_title(&self) -> String {
+        format!("Replace with `{}`", self.replacement)
+    }
+}
+
+/// Returns the [`Range`] spanning the full `lhs op rhs` comparison, so a fix
+/// can replace both operands and the operator in one edit rather than just
+/// the literal side that `Range::from(lhs)`/`Range::from(rhs)` would cover.
+///
+/// `is_first`/`is_last` say whether `lhs`/`rhs` are the absolute first/last
+/// operand of the enclosing `Compare` node (`expr`). We anchor on `expr`'s
+/// own location/end_location in that case rather than on `lhs`/`rhs`
+/// directly: a parenthesized operand's own location starts *after* its
+/// opening paren (e.g. in `(x) == ""`, `x` is at column 1, not 0), so
+/// reconstructing the range from the inner operand would leave a dangling
+/// `(` behind after the fix is applied.
+fn compare_range(expr: &Expr, lhs: &Expr, rhs: &Expr, is_first: bool, is_last: bool) -> Range {
+    let start = if is_first { expr.location } else { lhs.location };
+    let end = if is_last {
+        expr.end_location
+    } else {
+        rhs.end_location
+    };
+    Range::new(start, end.expect("expression must have an end location"))
+}
+
+/// Returns `true` if `expr` is a literal that is statically known to be
+/// falsey (an empty string/bytes, a zero-valued number, `False`, `None`, an
+/// empty container display, or `set()`), for which `== ""`-style comparisons
+/// can be simplified.
+///
+/// Only `==`/`!=` comparisons are simplified this way: `is`/`is not` against
+/// a mutable empty container (`[]`, `{}`, `set()`) is an identity check, not
+/// a truthiness check, so rewriting it to `not x` would change semantics.
+fn is_falsey_literal(expr: &Expr) -> bool {
+    static_truthiness(expr) == Some(false)
 }
 
 pub fn compare_to_empty_string(
     checker: &mut Checker,
+    expr: &Expr,
     left: &Expr,
     ops: &[Cmpop],
     comparators: &[Expr],
 ) {
-    let mut first = true;
-    for ((lhs, rhs), op) in std::iter::once(left)
+    let last_index = comparators.len().saturating_sub(1);
+    for (index, ((lhs, rhs), op)) in std::iter::once(left)
         .chain(comparators.iter())
         .tuple_windows::<(&Expr<_>, &Expr<_>)>()
         .zip(ops)
+        .enumerate()
     {
         if let Ok(op) = EmptyStringCmpop::try_from(op) {
-            if std::mem::take(&mut first) {
+            // `is`/`is not` against an empty container is an identity check,
+            // not a truthiness check, so only `==`/`!=` are simplified.
+            let is_eq_cmpop = matches!(op, EmptyStringCmpop::Eq | EmptyStringCmpop::NotEq);
+            let is_first = index == 0;
+            let is_last = index == last_index;
+
+            if is_first && is_eq_cmpop && is_falsey_literal(lhs) {
                 // Check the left-most expression.
-                if let ExprKind::Constant { value, .. } = &lhs.node {
-                    if let Constant::Str(s) = value {
-                        if s.is_empty() {
-                            let existing = format!(
-                                "{} {} {}",
-                                unparse_constant(value, checker.stylist),
-                                op,
-                                unparse_expr(rhs, checker.stylist)
-                            );
-                            let replacement = format!(
-                                "{}{}",
-                                op.into_unary(),
-                                unparse_expr(rhs, checker.stylist)
-                            );
-                            checker.diagnostics.push(Diagnostic::new(
-                                CompareToEmptyString {
-                                    existing,
-                                    replacement,
-                                },
-                                Range::from(lhs),
-                            ));
-                        }
-                    }
+                let existing = format!(
+                    "{} {} {}",
+                    unparse_expr(lhs, checker.stylist),
+                    op,
+                    unparse_expr(rhs, checker.stylist)
+                );
+                let replacement =
+                    format!("{}{}", op.into_unary(), unparse_expr(rhs, checker.stylist));
+                let range = compare_range(expr, lhs, rhs, is_first, is_last);
+                let mut diagnostic = Diagnostic::new(
+                    CompareToEmptyString {
+                        existing,
+                        replacement: replacement.clone(),
+                    },
+                    range,
+                );
+                if checker.patch(diagnostic.kind.rule()) {
+                    diagnostic.set_fix(Fix::replacement(
+                        replacement,
+                        range.location,
+                        range.end_location,
+                    ));
                 }
+                checker.diagnostics.push(diagnostic);
             }
 
             // Check all right-hand expressions.
-            if let ExprKind::Constant { value, .. } = &rhs.node {
-                if let Constant::Str(s) = value {
-                    if s.is_empty() {
-                        let existing = format!(
-                            "{} {} {}",
-                            unparse_expr(lhs, checker.stylist),
-                            op,
-                            unparse_constant(value, checker.stylist),
-                        );
-                        let replacement =
-                            format!("{}{}", op.into_unary(), unparse_expr(lhs, checker.stylist));
-                        checker.diagnostics.push(Diagnostic::new(
-                            CompareToEmptyString {
-                                existing,
-                                replacement,
-                            },
-                            Range::from(rhs),
-                        ));
-                    }
+            if is_eq_cmpop && is_falsey_literal(rhs) {
+                let existing = format!(
+                    "{} {} {}",
+                    unparse_expr(lhs, checker.stylist),
+                    op,
+                    unparse_expr(rhs, checker.stylist),
+                );
+                let replacement =
+                    format!("{}{}", op.into_unary(), unparse_expr(lhs, checker.stylist));
+                let range = compare_range(expr, lhs, rhs, is_first, is_last);
+                let mut diagnostic = Diagnostic::new(
+                    CompareToEmptyString {
+                        existing,
+                        replacement: replacement.clone(),
+                    },
+                    range,
+                );
+                if checker.patch(diagnostic.kind.rule()) {
+                    diagnostic.set_fix(Fix::replacement(
+                        replacement,
+                        range.location,
+                        range.end_location,
+                    ));
                 }
+                checker.diagnostics.push(diagnostic);
             }
         }
     }
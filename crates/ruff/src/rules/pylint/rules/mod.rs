@@ -0,0 +1,9 @@
+pub(crate) use compare_to_empty_string::{
+    compare_to_empty_string, CompareToEmptyString, EmptyStringCmpop,
+};
+pub(crate) use using_constant_test::{
+    using_constant_test, using_constant_test_in_bool_op, UsingConstantTest,
+};
+
+mod compare_to_empty_string;
+mod using_constant_test;
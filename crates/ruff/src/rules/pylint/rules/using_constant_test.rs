@@ -0,0 +1,126 @@
+use rustpython_parser::ast::{Boolop, Expr};
+
+use ruff_diagnostics::{AutofixKind, Diagnostic, Fix, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::helpers::{static_truthiness, unparse_expr};
+use ruff_python_ast::types::Range;
+
+use crate::checkers::ast::Checker;
+
+#[violation]
+pub struct UsingConstantTest {
+    pub truthiness: bool,
+    pub replacement: Option<String>,
+}
+
+impl Violation for UsingConstantTest {
+    const AUTOFIX: AutofixKind = AutofixKind::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        if self.truthiness {
+            "Using a constant test that is always truthy".to_string()
+        } else {
+            "Using a constant test that is always falsey".to_string()
+        }
+    }
+
+    fn autofix_title(&self) -> Option<String> {
+        self.replacement
+            .as_ref()
+            .map(|replacement| format!("Replace with `{replacement}`"))
+    }
+}
+
+/// Flag the test of an `if`/`while`/`assert` statement when its outcome is
+/// statically known, so the branch it guards is either always taken or
+/// always dead.
+///
+/// Skips the idiomatic `while True:` loop, which relies on a constant test
+/// by design and would otherwise make the rule noisy.
+///
+/// Called from `Checker::visit_stmt` for `StmtKind::{If, While, Assert}`.
+pub fn using_constant_test(checker: &mut Checker, test: &Expr, is_while_true: bool) {
+    if is_while_true {
+        return;
+    }
+
+    if let Some(truthiness) = static_truthiness(test) {
+        checker.diagnostics.push(Diagnostic::new(
+            UsingConstantTest {
+                truthiness,
+                // Removing a dead `if`/`while` block or a doomed `assert`
+                // is a structural edit, not a text replacement, so no fix
+                // is offered here yet.
+                replacement: None,
+            },
+            Range::from(test),
+        ));
+    }
+}
+
+/// Flag every statically-known operand of a boolean operator.
+///
+/// `and` short-circuits on a falsey operand, `or` on a truthy one: once we
+/// hit one, it pins the truthiness of the *whole* `BoolOp` (whether or not
+/// it's the last operand — if reached, it's either the short-circuiting
+/// value or the deterministic fallback), so the rest is dead code.
+///
+/// We only offer a fix collapsing the whole expression down to that operand
+/// (e.g. `"" and x` -> `""`) when it's the *first* operand: otherwise an
+/// earlier operand of unknown truthiness could short-circuit first with a
+/// different value (e.g. in `x and ""` with `x` bound to `0`, the result is
+/// `0`, not `""`), and rewriting to the matched operand would silently change
+/// the result.
+///
+/// The opposite combination (a truthy operand in `and`, a falsey one in
+/// `or`, e.g. the `""` in `"" or y`) doesn't determine the outcome; unless
+/// it's the last operand, it can be dropped without changing the result.
+/// We still flag it, but don't offer a fix: doing so safely needs the
+/// range of the surrounding operator, which we don't have here.
+///
+/// Called from `Checker::visit_expr` for `ExprKind::BoolOp`.
+pub fn using_constant_test_in_bool_op(
+    checker: &mut Checker,
+    expr: &Expr,
+    op: &Boolop,
+    values: &[Expr],
+) {
+    for (index, value) in values.iter().enumerate() {
+        let Some(truthiness) = static_truthiness(value) else {
+            continue;
+        };
+
+        let short_circuits = match op {
+            Boolop::And => !truthiness,
+            Boolop::Or => truthiness,
+        };
+
+        let replacement =
+            (short_circuits && index == 0).then(|| unparse_expr(value, checker.stylist));
+        let mut diagnostic = Diagnostic::new(
+            UsingConstantTest {
+                truthiness,
+                replacement: replacement.clone(),
+            },
+            Range::from(value),
+        );
+        if let Some(replacement) = replacement {
+            if checker.patch(diagnostic.kind.rule()) {
+                let range = Range::from(expr);
+                diagnostic.set_fix(Fix::replacement(
+                    replacement,
+                    range.location,
+                    range.end_location,
+                ));
+            }
+        }
+        checker.diagnostics.push(diagnostic);
+
+        // Once we hit an operand that pins the whole expression's
+        // truthiness, everything after it is unreachable.
+        if short_circuits {
+            break;
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use num_traits::Zero;
+use rustpython_parser::ast::{Constant, Expr, ExprKind};
+
+/// Return the statically-known truthiness of `expr`, if any.
+///
+/// Returns `Some(true)` or `Some(false)` when `expr` is a literal constant or
+/// an empty/non-empty container display whose truthiness Python can
+/// determine without running any code (`""`, `0`, `None`, `[]`, `(1, 2)`,
+/// `{"a": 1}`, ...). Python has no empty-set literal syntax, so `set()` --
+/// a bare, argument-less call to the builtin name `set` -- is special-cased
+/// as falsey too. Returns `None` for anything else — in particular, for
+/// names and other calls/operators — so callers never mistake a runtime-
+/// dependent or side-effecting expression for a known-constant one.
+pub fn static_truthiness(expr: &Expr) -> Option<bool> {
+    match &expr.node {
+        ExprKind::Constant { value, .. } => Some(match value {
+            Constant::Str(s) => !s.is_empty(),
+            Constant::Bytes(b) => !b.is_empty(),
+            Constant::Int(i) => !i.is_zero(),
+            Constant::Float(f) => *f != 0.0,
+            Constant::Complex { real, imag } => *real != 0.0 || *imag != 0.0,
+            Constant::Bool(b) => *b,
+            Constant::None => false,
+            Constant::Tuple(elts) => !elts.is_empty(),
+            Constant::Ellipsis => true,
+        }),
+        // A display is only known if every element is itself known: this
+        // rules out names, calls, and `*`/`**` unpacking (whose emptiness
+        // depends on runtime values).
+        ExprKind::List { elts, .. } | ExprKind::Tuple { elts, .. } | ExprKind::Set { elts, .. } => {
+            elts.iter()
+                .all(|elt| static_truthiness(elt).is_some())
+                .then(|| !elts.is_empty())
+        }
+        ExprKind::Dict { keys, values } => (keys
+            .iter()
+            .all(|key| key.as_ref().map_or(false, |key| static_truthiness(key).is_some()))
+            && values.iter().all(|value| static_truthiness(value).is_some()))
+        .then(|| !keys.is_empty()),
+        ExprKind::Call {
+            func,
+            args,
+            keywords,
+        } if args.is_empty() && keywords.is_empty() => match &func.node {
+            ExprKind::Name { id, .. } if id == "set" => Some(false),
+            _ => None,
+        },
+        _ => None,
+    }
+}